@@ -1,12 +1,18 @@
 /// Define a left and a right region for the application.
 /// Each region is divided in vertically stacked rectangles.
+use std::fs;
 use std::marker::PhantomData;
+use std::path::PathBuf;
 use std::rc::Rc;
 
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Margin, Rect},
     style::{Color, Style},
-    widgets::{block::Position, Block, BorderType, Borders, Padding},
+    text::Line,
+    widgets::{
+        block::Position, Block, BorderType, Borders, List, ListItem, ListState, Padding, Paragraph,
+        Scrollbar, ScrollbarOrientation, ScrollbarState,
+    },
     Frame,
 };
 
@@ -19,18 +25,36 @@ struct RegionInfo {
 struct RegionRectInfo {
     pub index: usize,
     pub title: &'static str,
-    pub height_percentage: u16,
+    pub constraint: Constraint,
     pub hotkey: char,
 }
 
 trait GetRegionInfo {
     fn get_region_info() -> RegionInfo;
     fn get_rect_info(&self) -> RegionRectInfo;
+    /// Ordered constraints for every rect of this region, indexed the same
+    /// way as [`RegionRectInfo::index`], so [`Region::solve`] can build the
+    /// vertical [`Layout`] without knowing the concrete variants.
+    fn get_rect_constraints() -> Vec<Constraint>;
+    /// Flex behavior applied to the region's vertical layout (e.g. to let
+    /// ratatui's cassowary solver space rects out rather than just stack
+    /// them).
+    fn get_flex() -> Flex {
+        Flex::Start
+    }
+    /// Gap inserted between stacked rects.
+    fn get_spacing() -> u16 {
+        0
+    }
 }
 
 pub(crate) struct Region<T: GetRegionInfo> {
     rects: Rc<[Rect]>,
     info: RegionInfo,
+    /// The `area` that `rects` was solved for, so [`Region::solve`] can skip
+    /// re-solving the cassowary layout when it's asked for the same area
+    /// again (e.g. a redraw on an unchanged frame size).
+    solved_area: Option<Rect>,
     _t: PhantomData<T>,
 }
 
@@ -39,19 +63,41 @@ impl<T: GetRegionInfo> Region<T> {
         Self {
             rects,
             info: T::get_region_info(),
+            solved_area: None,
             _t: PhantomData,
         }
     }
+
+    /// Solve the vertical [`Layout`] for this region inside `area`, caching
+    /// the resulting rects on `self` so redraws on an unchanged frame size
+    /// skip re-solving the cassowary layout.
+    fn solve(&mut self, area: Rect) {
+        if self.solved_area == Some(area) {
+            return;
+        }
+        self.rects = Layout::default()
+            .direction(Direction::Vertical)
+            .flex(T::get_flex())
+            .spacing(T::get_spacing())
+            .constraints(T::get_rect_constraints())
+            .split(area);
+        self.solved_area = Some(area);
+    }
 }
 
 // Left Region --------------------------------------------------------------
 
+#[derive(Clone, Copy)]
 pub(crate) enum LeftRegion {
     Top,
     Middle,
     Bottom,
 }
 
+impl LeftRegion {
+    const ALL: [LeftRegion; 3] = [LeftRegion::Top, LeftRegion::Middle, LeftRegion::Bottom];
+}
+
 impl GetRegionInfo for LeftRegion {
     fn get_region_info() -> RegionInfo {
         RegionInfo {
@@ -64,32 +110,45 @@ impl GetRegionInfo for LeftRegion {
             LeftRegion::Top => RegionRectInfo {
                 index: 0,
                 title: "Backend",
-                height_percentage: 30,
+                constraint: Constraint::Length(3),
                 hotkey: 'b',
             },
             LeftRegion::Middle => RegionRectInfo {
                 index: 1,
                 title: "Benches",
-                height_percentage: 60,
+                constraint: Constraint::Fill(1),
                 hotkey: 'n',
             },
             LeftRegion::Bottom => RegionRectInfo {
                 index: 2,
                 title: "Action",
-                height_percentage: 10,
+                constraint: Constraint::Length(3),
                 hotkey: 'a',
             },
         }
     }
+
+    fn get_rect_constraints() -> Vec<Constraint> {
+        vec![
+            LeftRegion::Top.get_rect_info().constraint,
+            LeftRegion::Middle.get_rect_info().constraint,
+            LeftRegion::Bottom.get_rect_info().constraint,
+        ]
+    }
 }
 
 // Right Region --------------------------------------------------------------
 
+#[derive(Clone, Copy)]
 pub(crate) enum RightRegion {
     Top,
     Bottom,
 }
 
+impl RightRegion {
+    const ALL: [RightRegion; 2] = [RightRegion::Top, RightRegion::Bottom];
+}
+
 impl GetRegionInfo for RightRegion {
     fn get_region_info() -> RegionInfo {
         RegionInfo {
@@ -102,17 +161,136 @@ impl GetRegionInfo for RightRegion {
             RightRegion::Top => RegionRectInfo {
                 index: 0,
                 title: "Results",
-                height_percentage: 90,
+                constraint: Constraint::Fill(1),
                 hotkey: 'r',
             },
             RightRegion::Bottom => RegionRectInfo {
                 index: 1,
                 title: "Progress",
-                height_percentage: 10,
+                constraint: Constraint::Length(3),
                 hotkey: 'p',
             },
         }
     }
+
+    fn get_rect_constraints() -> Vec<Constraint> {
+        vec![
+            RightRegion::Top.get_rect_info().constraint,
+            RightRegion::Bottom.get_rect_info().constraint,
+        ]
+    }
+}
+
+// Focus -----------------------------------------------------------------
+
+/// Which outer region (left or right) a focused rect belongs to.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Side {
+    Left,
+    Right,
+}
+
+/// Tracks which rect across both regions is currently active, so that
+/// hotkeys and Tab cycling can move focus between panes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Focus {
+    side: Side,
+    index: usize,
+}
+
+impl Default for Focus {
+    fn default() -> Self {
+        Self {
+            side: Side::Left,
+            index: 0,
+        }
+    }
+}
+
+impl Focus {
+    fn is_focused(&self, side: Side, index: usize) -> bool {
+        self.side == side && self.index == index
+    }
+}
+
+// Region content --------------------------------------------------------
+
+/// Scrollable, selectable content rendered inside a region's bordered
+/// frame, with a [`Scrollbar`] drawn on the right edge when the rows
+/// overflow the rect's viewport.
+pub(crate) struct RegionContent {
+    rows: Vec<String>,
+    top: usize,
+    selected: usize,
+}
+
+impl RegionContent {
+    pub fn new(rows: Vec<String>) -> Self {
+        Self {
+            rows,
+            top: 0,
+            selected: 0,
+        }
+    }
+
+    /// Replace the rows, clamping `selected` to the new length and `top` so
+    /// the viewport doesn't land past the end of a shrunk list.
+    pub fn set_rows(&mut self, rows: Vec<String>, viewport_height: usize) {
+        self.rows = rows;
+        self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+        self.top = self.top.min(self.rows.len().saturating_sub(viewport_height));
+    }
+
+    /// Move the selection to `selected`, scrolling the viewport just enough
+    /// to keep it visible: if the selection moved past the bottom, snap the
+    /// top so it lands on the last visible row; if it moved above the top,
+    /// snap the top to the selection.
+    pub fn select(&mut self, selected: usize, viewport_height: usize) {
+        self.selected = selected.min(self.rows.len().saturating_sub(1));
+        if viewport_height == 0 {
+            return;
+        }
+        if self.top + viewport_height <= self.selected {
+            self.top = self.selected + 1 - viewport_height;
+        } else if self.top > self.selected {
+            self.top = self.selected;
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, block: Block, area: Rect) {
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let viewport_height = inner.height as usize;
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
+            .skip(self.top)
+            .take(viewport_height)
+            .map(|row| ListItem::new(row.as_str()))
+            .collect();
+        let mut list_state = ListState::default();
+        if self.selected >= self.top && self.selected - self.top < viewport_height {
+            list_state.select(Some(self.selected - self.top));
+        }
+        frame.render_stateful_widget(List::new(items), inner, &mut list_state);
+
+        if self.rows.len() > viewport_height {
+            // `ScrollbarState::new` wants the actual content length, not
+            // the scroll range, so the thumb is sized proportionally to
+            // how much content there really is.
+            let mut scrollbar_state =
+                ScrollbarState::new(self.rows.len()).position(self.top);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                area.inner(Margin {
+                    vertical: 1,
+                    horizontal: 0,
+                }),
+                &mut scrollbar_state,
+            );
+        }
+    }
 }
 
 // Regions definition --------------------------------------------------------
@@ -120,85 +298,314 @@ impl GetRegionInfo for RightRegion {
 pub(crate) struct Regions<L: GetRegionInfo, R: GetRegionInfo> {
     pub left: Region<L>,
     pub right: Region<R>,
+    focus: Focus,
+    benches: RegionContent,
+    results: RegionContent,
+    frame_size: Rect,
+    min_width: u16,
+    /// Percentage width given to the left region; the right region gets the
+    /// rest, minus the one-column divider between them.
+    split_ratio: u16,
+    divider_rect: Rect,
 }
 
 impl Regions<LeftRegion, RightRegion> {
+    /// Combined, ordered list of every focusable slot, used for Tab
+    /// cycling.
+    const SLOTS: [(Side, usize); 5] = [
+        (Side::Left, 0),
+        (Side::Left, 1),
+        (Side::Left, 2),
+        (Side::Right, 0),
+        (Side::Right, 1),
+    ];
+
+    /// Below this terminal width, the left/right regions stack vertically
+    /// (left "above", right "below") instead of sitting side by side.
+    const DEFAULT_MIN_WIDTH: u16 = 100;
+
     pub fn new(frame: &Frame) -> Self {
+        Self::new_with_min_width(frame, Self::DEFAULT_MIN_WIDTH)
+    }
+
+    pub fn new_with_min_width(frame: &Frame, min_width: u16) -> Self {
+        Self::from_frame_size(frame.size(), min_width)
+    }
+
+    fn from_frame_size(frame_size: Rect, min_width: u16) -> Self {
+        let mut regions = Self {
+            left: Region::new(Rc::from([])),
+            right: Region::new(Rc::from([])),
+            focus: Focus::default(),
+            benches: RegionContent::new(Vec::new()),
+            results: RegionContent::new(Vec::new()),
+            frame_size,
+            min_width,
+            split_ratio: LeftRegion::get_region_info().width_percentage,
+            divider_rect: Rect::default(),
+        };
+        regions.split_ratio = regions.clamp_split_ratio(Self::load_saved_split_ratio());
+        regions.recompute_outer();
+        regions
+    }
+
+    fn direction(&self) -> Direction {
+        if self.frame_size.width < self.min_width {
+            Direction::Vertical
+        } else {
+            Direction::Horizontal
+        }
+    }
+
+    /// Re-solve the outer left/divider/right split for the current
+    /// `frame_size` and `split_ratio`.
+    fn recompute_outer(&mut self) {
         let outer_layout = Layout::default()
-            .direction(Direction::Horizontal)
+            .direction(self.direction())
             .constraints(vec![
-                Constraint::Percentage(LeftRegion::get_region_info().width_percentage),
-                Constraint::Percentage(RightRegion::get_region_info().width_percentage),
+                Constraint::Percentage(self.split_ratio),
+                Constraint::Length(1),
+                Constraint::Percentage(100 - self.split_ratio),
             ])
-            .split(frame.size());
-        let left_rects = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(vec![
-                Constraint::Percentage(LeftRegion::Top.get_rect_info().height_percentage),
-                Constraint::Percentage(LeftRegion::Middle.get_rect_info().height_percentage),
-                Constraint::Percentage(LeftRegion::Bottom.get_rect_info().height_percentage),
-            ])
-            .split(outer_layout[0]);
-        let right_rects = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(vec![
-                Constraint::Percentage(RightRegion::Top.get_rect_info().height_percentage),
-                Constraint::Percentage(RightRegion::Bottom.get_rect_info().height_percentage),
-            ])
-            .split(outer_layout[1]);
-        Self::new_with_rect(left_rects, right_rects)
+            .split(self.frame_size);
+        self.divider_rect = outer_layout[1];
+        self.left.solve(outer_layout[0]);
+        self.right.solve(outer_layout[2]);
+    }
+
+    fn split_ratio_config_path() -> PathBuf {
+        std::env::var_os("HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir)
+            .join(".burn-bench-tui-split-ratio")
+    }
+
+    fn load_saved_split_ratio() -> u16 {
+        fs::read_to_string(Self::split_ratio_config_path())
+            .ok()
+            .and_then(|content| content.trim().parse().ok())
+            .unwrap_or_else(|| LeftRegion::get_region_info().width_percentage)
+    }
+
+    /// Bounds on the draggable split ratio, so a side never shrinks past the
+    /// point where its own padding ([`Region::block`]) would no longer fit.
+    ///
+    /// Mirrors `Region::block`'s own `rect.width / 8` padding formula (plus
+    /// the one-column border on each edge) against the current
+    /// `frame_size`, rather than a fixed percentage, so the floor tightens
+    /// on a narrow terminal and relaxes on a wide one.
+    fn split_ratio_bounds(&self) -> (u16, u16) {
+        let extent = match self.direction() {
+            Direction::Horizontal => self.frame_size.width,
+            Direction::Vertical => self.frame_size.height,
+        };
+        if extent == 0 {
+            return (0, 100);
+        }
+        let min_span = (extent / 8).max(1) + 2;
+        let min_ratio = (min_span as u32 * 100)
+            .div_ceil(extent as u32)
+            .min(50) as u16;
+        (min_ratio, 100 - min_ratio)
     }
 
-    pub fn draw(&self, frame: &mut Frame) {
+    fn clamp_split_ratio(&self, ratio: u16) -> u16 {
+        let (min, max) = self.split_ratio_bounds();
+        ratio.clamp(min, max)
+    }
+
+    fn set_split_ratio(&mut self, ratio: u16) {
+        let ratio = self.clamp_split_ratio(ratio);
+        if ratio == self.split_ratio {
+            return;
+        }
+        self.split_ratio = ratio;
+        // Only touch disk when the ratio actually moved, so a mouse drag
+        // spanning many ticks at the same column doesn't write on every one.
+        let _ = fs::write(
+            Self::split_ratio_config_path(),
+            self.split_ratio.to_string(),
+        );
+        self.recompute_outer();
+    }
+
+    /// Resize the left region by moving the divider to `position` (a mouse
+    /// column for a horizontal split, a row for a stacked one).
+    pub fn drag_divider(&mut self, position: u16) {
+        let (offset, extent) = match self.direction() {
+            Direction::Horizontal => (self.frame_size.x, self.frame_size.width),
+            Direction::Vertical => (self.frame_size.y, self.frame_size.height),
+        };
+        if extent == 0 {
+            return;
+        }
+        // Stay in u32 and saturate to 100 before narrowing, so a drag past
+        // the edge of the frame can't wrap around to a bogus small ratio.
+        let ratio = (position.saturating_sub(offset) as u32 * 100 / extent as u32).min(100) as u16;
+        self.set_split_ratio(ratio);
+    }
+
+    /// Shrink the left region, e.g. bound to the `<` key.
+    pub fn shrink_left(&mut self) {
+        self.set_split_ratio(self.split_ratio.saturating_sub(2));
+    }
+
+    /// Grow the left region, e.g. bound to the `>` key.
+    pub fn grow_left(&mut self) {
+        self.set_split_ratio(self.split_ratio.saturating_add(2));
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame) {
+        // Pick up terminal resizes: `Regions` now carries persistent state
+        // across frames, so nothing else re-solves the outer split when the
+        // frame size changes.
+        if frame.size() != self.frame_size {
+            self.frame_size = frame.size();
+            self.recompute_outer();
+        }
         // Left region
         frame.render_widget(
-            self.left.block(LeftRegion::Top),
+            self.left
+                .block(LeftRegion::Top, self.is_focused(Side::Left, 0)),
             self.left.rect(LeftRegion::Top),
         );
-        frame.render_widget(
-            self.left.block(LeftRegion::Middle),
+        self.benches.render(
+            frame,
+            self.left
+                .block(LeftRegion::Middle, self.is_focused(Side::Left, 1)),
             self.left.rect(LeftRegion::Middle),
         );
         frame.render_widget(
-            self.left.block(LeftRegion::Bottom),
+            self.left
+                .block(LeftRegion::Bottom, self.is_focused(Side::Left, 2)),
             self.left.rect(LeftRegion::Bottom),
         );
         // Right region
-        frame.render_widget(
-            self.right.block(RightRegion::Top),
+        self.results.render(
+            frame,
+            self.right
+                .block(RightRegion::Top, self.is_focused(Side::Right, 0)),
             self.right.rect(RightRegion::Top),
         );
         frame.render_widget(
-            self.right.block(RightRegion::Bottom),
+            self.right
+                .block(RightRegion::Bottom, self.is_focused(Side::Right, 1)),
             self.right.rect(RightRegion::Bottom),
         );
+        self.render_divider(frame);
     }
-}
 
-impl<L: GetRegionInfo, R: GetRegionInfo> Regions<L, R> {
-    fn new_with_rect(left_rects: Rc<[Rect]>, right_rects: Rc<[Rect]>) -> Self {
-        Self {
-            left: Region {
-                rects: left_rects,
-                info: L::get_region_info(),
-                _t: PhantomData,
-            },
-            right: Region {
-                rects: right_rects,
-                info: R::get_region_info(),
-                _t: PhantomData,
-            },
+    /// Draw the thin, draggable line separating the left and right regions.
+    fn render_divider(&self, frame: &mut Frame) {
+        let lines = match self.direction() {
+            Direction::Horizontal => {
+                vec![Line::raw("│"); self.divider_rect.height as usize]
+            }
+            Direction::Vertical => {
+                vec![Line::raw("─".repeat(self.divider_rect.width as usize))]
+            }
+        };
+        frame.render_widget(Paragraph::new(lines), self.divider_rect);
+    }
+
+    /// Replace the benchmark list shown in the "Benches" pane.
+    pub fn set_benches(&mut self, rows: Vec<String>) {
+        let viewport_height = self.left.inner_rect(LeftRegion::Middle).height as usize;
+        self.benches.set_rows(rows, viewport_height);
+    }
+
+    /// Move the selection in the "Benches" pane, scrolling it into view.
+    pub fn select_bench(&mut self, selected: usize) {
+        let viewport_height = self.left.inner_rect(LeftRegion::Middle).height as usize;
+        self.benches.select(selected, viewport_height);
+    }
+
+    /// Replace the benchmark output shown in the "Results" pane.
+    pub fn set_results(&mut self, rows: Vec<String>) {
+        let viewport_height = self.right.inner_rect(RightRegion::Top).height as usize;
+        self.results.set_rows(rows, viewport_height);
+    }
+
+    fn is_focused(&self, side: Side, index: usize) -> bool {
+        self.focus.is_focused(side, index)
+    }
+
+    /// Resolve a pressed key to a region/rect via the hotkey table and, if
+    /// found, focus it. Returns whether a match was found.
+    pub fn focus_by_hotkey(&mut self, key: char) -> bool {
+        for region in LeftRegion::ALL {
+            let info = region.get_rect_info();
+            if info.hotkey == key {
+                self.focus = Focus {
+                    side: Side::Left,
+                    index: info.index,
+                };
+                return true;
+            }
+        }
+        for region in RightRegion::ALL {
+            let info = region.get_rect_info();
+            if info.hotkey == key {
+                self.focus = Focus {
+                    side: Side::Right,
+                    index: info.index,
+                };
+                return true;
+            }
         }
+        false
+    }
+
+    fn slot_index(&self) -> usize {
+        Self::SLOTS
+            .iter()
+            .position(|&(side, index)| side == self.focus.side && index == self.focus.index)
+            .unwrap_or(0)
+    }
+
+    /// Move focus to the next rect, cycling from the right region back to
+    /// the left one.
+    pub fn focus_next(&mut self) {
+        let next = (self.slot_index() + 1) % Self::SLOTS.len();
+        let (side, index) = Self::SLOTS[next];
+        self.focus = Focus { side, index };
+    }
+
+    /// Move focus to the previous rect, cycling from the left region back
+    /// to the right one.
+    pub fn focus_prev(&mut self) {
+        let prev = (self.slot_index() + Self::SLOTS.len() - 1) % Self::SLOTS.len();
+        let (side, index) = Self::SLOTS[prev];
+        self.focus = Focus { side, index };
     }
 }
 
-impl<P: GetRegionInfo> Region<P> {
+impl<P: GetRegionInfo + Copy> Region<P> {
     pub fn rect(&self, position: P) -> Rect {
         self.rects[position.get_rect_info().index]
     }
 
-    /// Widget to draw the style of a region
-    fn block(&self, position: P) -> Block {
+    /// The rect's area once its border and padding are accounted for, i.e.
+    /// the viewport available to its content.
+    pub fn inner_rect(&self, position: P) -> Rect {
+        self.block(position, false).inner(self.rect(position))
+    }
+
+    /// Widget to draw the style of a region. `focused` draws a brighter,
+    /// double-lined border so users can see which pane hotkeys/Tab will act
+    /// on.
+    fn block(&self, position: P, focused: bool) -> Block {
+        let (border_style, border_type) = if focused {
+            (Style::default().fg(Color::Yellow), BorderType::Double)
+        } else {
+            (Style::default().fg(Color::DarkGray), BorderType::Rounded)
+        };
+        let rect = self.rects[position.get_rect_info().index];
+        // Scale padding to the rect's own size instead of a fixed amount, so
+        // small rects (e.g. a narrow-terminal stacked layout) don't have
+        // their inner area eaten alive by padding sized for a wide one.
+        let horizontal_padding = (rect.width / 8).max(1);
+        let vertical_padding = (rect.height / 8).max(1);
         Block::default()
             .title(format!(
                 "{} ({})",
@@ -208,13 +615,13 @@ impl<P: GetRegionInfo> Region<P> {
             .title_position(Position::Top)
             .title_alignment(Alignment::Center)
             .borders(Borders::all())
-            .border_style(Style::default().fg(Color::DarkGray))
-            .border_type(BorderType::Rounded)
+            .border_style(border_style)
+            .border_type(border_type)
             .padding(Padding {
-                left: 10,
-                right: 10,
-                top: 2,
-                bottom: 2,
+                left: horizontal_padding,
+                right: horizontal_padding,
+                top: vertical_padding,
+                bottom: vertical_padding,
             })
             .style(Style::default().bg(Color::Black))
     }
@@ -222,4 +629,113 @@ impl<P: GetRegionInfo> Region<P> {
 
 fn create_region_block(title: &str) -> Block {
     todo!()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+impl Regions<LeftRegion, RightRegion> {
+    /// Build `Regions` directly from a `frame_size`, bypassing the real
+    /// `ratatui::Frame` that `new`/`new_with_min_width` need just to read
+    /// `frame.size()`, so the pure layout/focus/drag logic below is
+    /// testable without a live terminal.
+    fn new_for_test(frame_size: Rect, min_width: u16) -> Self {
+        Self::from_frame_size(frame_size, min_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_scrolls_down_to_keep_selection_in_view() {
+        let mut content = RegionContent::new((0..10).map(|i| i.to_string()).collect());
+        content.select(5, 3);
+        assert_eq!(content.top, 3);
+        assert_eq!(content.selected, 5);
+    }
+
+    #[test]
+    fn select_scrolls_up_to_keep_selection_in_view() {
+        let mut content = RegionContent::new((0..10).map(|i| i.to_string()).collect());
+        content.select(5, 3);
+        content.select(1, 3);
+        assert_eq!(content.top, 1);
+        assert_eq!(content.selected, 1);
+    }
+
+    #[test]
+    fn set_rows_clamps_stale_top_when_rows_shrink() {
+        let mut content = RegionContent::new((0..10).map(|i| i.to_string()).collect());
+        let viewport_height = 3;
+        content.select(9, viewport_height);
+        assert_eq!(content.top, 7);
+
+        content.set_rows((0..3).map(|i| i.to_string()).collect(), viewport_height);
+
+        assert_eq!(content.top, 0);
+        let visible: Vec<_> = content
+            .rows
+            .iter()
+            .skip(content.top)
+            .take(viewport_height)
+            .collect();
+        assert_eq!(visible.len(), 3);
+    }
+
+    #[test]
+    fn focus_by_hotkey_focuses_matching_slot() {
+        let mut regions = Regions::new_for_test(Rect::new(0, 0, 120, 40), 100);
+        assert!(regions.focus_by_hotkey('r'));
+        assert!(regions.is_focused(Side::Right, 0));
+        assert!(!regions.focus_by_hotkey('z'));
+    }
+
+    #[test]
+    fn drag_divider_clamps_instead_of_overflowing() {
+        let mut regions = Regions::new_for_test(Rect::new(0, 0, 120, 40), 100);
+        regions.drag_divider(u16::MAX);
+        let (_, max_ratio) = regions.split_ratio_bounds();
+        assert_eq!(regions.split_ratio, max_ratio);
+    }
+
+    #[test]
+    fn region_solve_hits_its_own_cache_on_unchanged_area() {
+        let mut region = Region::<LeftRegion>::new(Rc::from([]));
+        let area = Rect::new(0, 0, 40, 40);
+
+        region.solve(area);
+        let first = region.rects.clone();
+        region.solve(area);
+
+        assert!(Rc::ptr_eq(&first, &region.rects));
+    }
+
+    #[test]
+    fn region_solve_invalidates_on_area_change() {
+        let mut region = Region::<LeftRegion>::new(Rc::from([]));
+
+        region.solve(Rect::new(0, 0, 40, 40));
+        let first = region.rects.clone();
+        region.solve(Rect::new(0, 0, 80, 80));
+
+        assert!(!Rc::ptr_eq(&first, &region.rects));
+        assert_ne!(first[..], region.rects[..]);
+    }
+
+    #[test]
+    fn region_solve_caches_per_instance_not_globally() {
+        let mut a = Region::<LeftRegion>::new(Rc::from([]));
+        let mut b = Region::<LeftRegion>::new(Rc::from([]));
+
+        a.solve(Rect::new(0, 0, 40, 40));
+        let a_rects = a.rects.clone();
+        // Solving `b` for a different area must not thrash `a`'s cache: a
+        // type-keyed global cache (rather than a per-instance one) would
+        // make this re-solve of `a` return `b`'s rects instead.
+        b.solve(Rect::new(0, 0, 80, 80));
+        a.solve(Rect::new(0, 0, 40, 40));
+
+        assert!(Rc::ptr_eq(&a_rects, &a.rects));
+        assert_ne!(a.rects[..], b.rects[..]);
+    }
+}